@@ -0,0 +1,8 @@
+pub mod sheet;
+pub mod vba;
+
+pub use sheet::{
+    CalamineCellIterator, CalamineFormulaIterator, CalamineSheet, CalamineUsedCellIterator,
+    SheetMetadata, SheetTypeEnum, SheetVisibleEnum,
+};
+pub use vba::CalamineVbaProject;
@@ -2,7 +2,7 @@ use std::fmt::Display;
 use std::sync::{Arc,Mutex};
 use std::collections::HashSet;
 
-use calamine::{Data, Range, Rows, SheetType, SheetVisible};
+use calamine::{CellType, Data, Range, Rows, SheetType, SheetVisible, UsedCells};
 use pyo3::class::basic::CompareOp;
 use pyo3::exceptions::PyValueError;
 use pyo3::prelude::*;
@@ -129,12 +129,72 @@ impl SheetMetadata {
     }
 }
 
+/// Shared `to_python`/`to_formula` row-windowing logic.
+fn windowed_range<T: CellType>(
+    range: &Arc<Range<T>>,
+    skip_empty_area: bool,
+    nrows: u32,
+) -> Arc<Range<T>> {
+    if skip_empty_area || Some((0, 0)) == range.start() {
+        Arc::clone(range)
+    } else if let Some(end) = range.end() {
+        Arc::new(range.range(
+            (0, 0),
+            (if nrows > end.0 { end.0 } else { nrows - 1 }, end.1),
+        ))
+    } else {
+        Arc::clone(range)
+    }
+}
+
+/// Shared `to_python`/`iter_rows` `start_row`/`start_col`/`end_col` windowing, with the
+/// same bounds validation `merge_cells` applies before slicing. `end_row` is always the
+/// incoming range's own end row rather than a caller-supplied value, so (unlike
+/// `merge_cells`) only `end_col` needs an explicit out-of-bounds check; `start_row`/
+/// `start_col` only need the invalid-order check against it.
+fn apply_start_window<T: CellType>(
+    range: Arc<Range<T>>,
+    start_row: Option<u32>,
+    start_col: Option<u32>,
+    end_col: Option<u32>,
+) -> PyResult<Arc<Range<T>>> {
+    if start_row.is_none() && start_col.is_none() && end_col.is_none() {
+        return Ok(range);
+    }
+
+    let Some(end) = range.end() else {
+        return Ok(range);
+    };
+
+    let start_row = start_row.unwrap_or(0);
+    let start_col = start_col.unwrap_or(0);
+    let end_col = end_col.unwrap_or(end.1);
+
+    if start_row > end.0 || start_col > end_col {
+        return Err(PyValueError::new_err(
+            "Invalid range: start position must be less than or equal to end position",
+        ));
+    }
+
+    if end_col > end.1 {
+        return Err(PyValueError::new_err(format!(
+            "Range out of bounds. Sheet size is {}x{}, but tried to access column {}",
+            end.1 + 1,
+            end.0 + 1,
+            end_col
+        )));
+    }
+
+    Ok(Arc::new(range.range((start_row, start_col), (end.0, end_col))))
+}
+
 #[pyclass]
 pub struct CalamineSheet {
     #[pyo3(get)]
     name: String,
     range: Arc<Range<Data>>,
-    merged_cells: Arc<Mutex<HashSet<(u32, u32, u32, u32)>>>
+    merged_cells: Arc<Mutex<HashSet<(u32, u32, u32, u32)>>>,
+    formula_range: Option<Arc<Range<String>>>,
 }
 
 impl CalamineSheet {
@@ -143,8 +203,53 @@ impl CalamineSheet {
             name,
             range: Arc::new(range),
             merged_cells: Arc::new(Mutex::new(HashSet::new())),
+            formula_range: None,
+        }
+    }
+
+    /// Like `new`, pre-populated with merged regions read from the workbook.
+    pub fn with_merged_regions(
+        name: String,
+        range: Range<Data>,
+        merged_regions: Vec<(u32, u32, u32, u32)>,
+    ) -> Self {
+        CalamineSheet {
+            name,
+            range: Arc::new(range),
+            merged_cells: Arc::new(Mutex::new(merged_regions.into_iter().collect())),
+            formula_range: None,
         }
     }
+
+    /// Attach the workbook's formula range, enabling `to_formula`/`iter_formula_rows`.
+    pub fn with_formulas(mut self, formula_range: Range<String>) -> Self {
+        self.formula_range = Some(Arc::new(formula_range));
+        self
+    }
+
+    /// Clone the range with each merged region's anchor value propagated across it.
+    fn filled_range(&self) -> PyResult<Range<Data>> {
+        let mut range = (*self.range).clone();
+        let merged_cells = self
+            .merged_cells
+            .lock()
+            .map_err(|_| PyValueError::new_err("Failed to access merged cells information"))?;
+
+        for &(start_row, start_col, end_row, end_col) in merged_cells.iter() {
+            let anchor = range.get_value((start_row, start_col)).cloned();
+            let Some(anchor) = anchor else { continue };
+
+            for row in start_row..=end_row {
+                for col in start_col..=end_col {
+                    if (row, col) != (start_row, start_col) {
+                        range.set_value((row, col), anchor.clone());
+                    }
+                }
+            }
+        }
+
+        Ok(range)
+    }
 }
 
 #[pymethods]
@@ -183,28 +288,30 @@ impl CalamineSheet {
         self.range.end()
     }
 
-    #[pyo3(signature = (skip_empty_area=true, nrows=None))]
+    #[pyo3(signature = (skip_empty_area=true, nrows=None, fill_merged=false, start_row=None, start_col=None, end_col=None))]
     fn to_python(
         slf: PyRef<'_, Self>,
         skip_empty_area: bool,
         nrows: Option<u32>,
+        fill_merged: bool,
+        start_row: Option<u32>,
+        start_col: Option<u32>,
+        end_col: Option<u32>,
     ) -> PyResult<Bound<'_, PyList>> {
         let nrows = match nrows {
             Some(nrows) => nrows,
             None => slf.range.end().map_or(0, |end| end.0 + 1),
         };
 
-        let range = if skip_empty_area || Some((0, 0)) == slf.range.start() {
-            Arc::clone(&slf.range)
-        } else if let Some(end) = slf.range.end() {
-            Arc::new(slf.range.range(
-                (0, 0),
-                (if nrows > end.0 { end.0 } else { nrows - 1 }, end.1),
-            ))
+        let source = if fill_merged {
+            Arc::new(slf.filled_range()?)
         } else {
             Arc::clone(&slf.range)
         };
 
+        let range = windowed_range(&source, skip_empty_area, nrows);
+        let range = apply_start_window(range, start_row, start_col, end_col)?;
+
         PyList::new(
             slf.py(),
             range.rows().take(nrows as usize).map(|row| {
@@ -214,8 +321,69 @@ impl CalamineSheet {
         .map_err(Into::into)
     }
 
-    fn iter_rows(&self) -> CalamineCellIterator {
-        CalamineCellIterator::from_range(Arc::clone(&self.range))
+    #[pyo3(signature = (skip_empty_area=true, nrows=None))]
+    fn to_formula(
+        slf: PyRef<'_, Self>,
+        skip_empty_area: bool,
+        nrows: Option<u32>,
+    ) -> PyResult<Bound<'_, PyList>> {
+        let formulas = slf
+            .formula_range
+            .clone()
+            .ok_or_else(|| PyValueError::new_err("Formula data is not available for this sheet"))?;
+
+        let nrows = match nrows {
+            Some(nrows) => nrows,
+            None => formulas.end().map_or(0, |end| end.0 + 1),
+        };
+
+        let range = windowed_range(&formulas, skip_empty_area, nrows);
+
+        PyList::new(
+            slf.py(),
+            range
+                .rows()
+                .take(nrows as usize)
+                .map(|row| PyList::new(slf.py(), row.iter().cloned()).unwrap()),
+        )
+        .map_err(Into::into)
+    }
+
+    #[pyo3(signature = (start_row=None, start_col=None, end_col=None, fill_merged=false))]
+    fn iter_rows(
+        &self,
+        start_row: Option<u32>,
+        start_col: Option<u32>,
+        end_col: Option<u32>,
+        fill_merged: bool,
+    ) -> PyResult<CalamineCellIterator> {
+        let source = if fill_merged {
+            Arc::new(self.filled_range()?)
+        } else {
+            Arc::clone(&self.range)
+        };
+
+        let windowed = start_row.is_some() || start_col.is_some() || end_col.is_some();
+        let range = apply_start_window(source, start_row, start_col, end_col)?;
+
+        Ok(if windowed {
+            CalamineCellIterator::from_windowed_range(range)
+        } else {
+            CalamineCellIterator::from_range(range)
+        })
+    }
+
+    fn iter_used_cells(&self) -> CalamineUsedCellIterator {
+        CalamineUsedCellIterator::from_range(Arc::clone(&self.range))
+    }
+
+    fn iter_formula_rows(&self) -> PyResult<CalamineFormulaIterator> {
+        let formulas = self
+            .formula_range
+            .clone()
+            .ok_or_else(|| PyValueError::new_err("Formula data is not available for this sheet"))?;
+
+        Ok(CalamineFormulaIterator::from_range(formulas))
     }
 
 
@@ -237,7 +405,7 @@ impl CalamineSheet {
 
         let mut range = (*self.range).clone();
         let mut merged_value = None;
-        
+
         if let Some(cell) = range.get_value((start_row as u32, start_col as u32)) {
             merged_value = Some(cell.clone());
         }
@@ -254,6 +422,10 @@ impl CalamineSheet {
             }
         }
 
+        // Persist the mutated range so the blanked-out non-anchor cells are actually
+        // visible to later reads, instead of discarding them along with the clone.
+        self.range = Arc::new(range);
+
         if let Ok(mut merged_cells) = self.merged_cells.lock() {
             merged_cells.insert((start_row, start_col, end_row, end_col));
         }
@@ -283,14 +455,14 @@ pub struct CalamineCellIterator {
 }
 
 impl CalamineCellIterator {
-    fn from_range(range: Arc<Range<Data>>) -> CalamineCellIterator {
+    fn new(range: Arc<Range<Data>>, start: (u32, u32)) -> CalamineCellIterator {
         let empty_row = (0..range.width())
             .map(|_| CellValue::String("".to_string()))
             .collect();
         CalamineCellIterator {
             empty_row,
             position: 0,
-            start: range.start().unwrap(),
+            start,
             iter: unsafe {
                 std::mem::transmute::<
                     calamine::Rows<'_, calamine::Data>,
@@ -300,6 +472,18 @@ impl CalamineCellIterator {
             range,
         }
     }
+
+    fn from_range(range: Arc<Range<Data>>) -> CalamineCellIterator {
+        let start = range.start().unwrap();
+        CalamineCellIterator::new(range, start)
+    }
+
+    // An explicit row/column window is the data the caller asked for, not a sheet's
+    // natural leading empty area, so unlike `from_range` no padding rows are added
+    // in front of it.
+    fn from_windowed_range(range: Arc<Range<Data>>) -> CalamineCellIterator {
+        CalamineCellIterator::new(range, (0, 0))
+    }
 }
 
 #[pymethods]
@@ -323,3 +507,138 @@ impl CalamineCellIterator {
         }
     }
 }
+
+/// Iterates only the populated cells of a sheet.
+#[pyclass]
+pub struct CalamineUsedCellIterator {
+    iter: UsedCells<'static, Data>,
+    #[allow(dead_code)]
+    range: Arc<Range<Data>>,
+}
+
+impl CalamineUsedCellIterator {
+    fn from_range(range: Arc<Range<Data>>) -> CalamineUsedCellIterator {
+        CalamineUsedCellIterator {
+            iter: unsafe {
+                std::mem::transmute::<
+                    calamine::UsedCells<'_, calamine::Data>,
+                    calamine::UsedCells<'static, calamine::Data>,
+                >(range.used_cells())
+            },
+            range,
+        }
+    }
+}
+
+#[pymethods]
+impl CalamineUsedCellIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> Option<(u32, u32, CellValue)> {
+        slf.iter
+            .next()
+            .map(|(row, col, value)| (row as u32, col as u32, value.into()))
+    }
+}
+
+/// Like [`CalamineCellIterator`], but over a sheet's formula range.
+#[pyclass]
+pub struct CalamineFormulaIterator {
+    position: u32,
+    start: (u32, u32),
+    empty_row: Vec<String>,
+    iter: Rows<'static, String>,
+    #[allow(dead_code)]
+    range: Arc<Range<String>>,
+}
+
+impl CalamineFormulaIterator {
+    fn from_range(range: Arc<Range<String>>) -> CalamineFormulaIterator {
+        let empty_row = (0..range.width()).map(|_| String::new()).collect();
+        CalamineFormulaIterator {
+            empty_row,
+            position: 0,
+            // A sheet with no formulas at all has an empty range with no `start()`;
+            // treat that as "no leading padding" rather than panicking.
+            start: range.start().unwrap_or((0, 0)),
+            iter: unsafe {
+                std::mem::transmute::<calamine::Rows<'_, String>, calamine::Rows<'static, String>>(
+                    range.rows(),
+                )
+            },
+            range,
+        }
+    }
+}
+
+#[pymethods]
+impl CalamineFormulaIterator {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(mut slf: PyRefMut<'_, Self>) -> PyResult<Option<Bound<'_, PyList>>> {
+        slf.position += 1;
+        if slf.position > slf.start.0 {
+            slf.iter
+                .next()
+                .map(|row| PyList::new(slf.py(), row.iter().cloned()).map_err(Into::into))
+                .transpose()
+        } else {
+            Some(PyList::new(slf.py(), slf.empty_row.clone()).map_err(Into::into)).transpose()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calamine::Cell;
+
+    fn range_10x1() -> Range<Data> {
+        Range::from_sparse((0..10).map(|row| Cell::new((row, 0), Data::Int(row as i64))).collect())
+    }
+
+    #[test]
+    fn windowed_iterator_has_no_leading_padding() {
+        let range = range_10x1();
+        let windowed = Arc::new(range.range((5, 0), (9, 0)));
+        assert_eq!(windowed.rows().count(), 5);
+
+        let iter = CalamineCellIterator::from_windowed_range(Arc::clone(&windowed));
+        assert_eq!(iter.start, (0, 0));
+    }
+
+    #[test]
+    fn formula_iterator_does_not_panic_on_empty_range() {
+        let empty: Range<String> = Range::default();
+        let iter = CalamineFormulaIterator::from_range(Arc::new(empty));
+        assert_eq!(iter.start, (0, 0));
+    }
+
+    #[test]
+    fn start_window_rejects_start_row_past_a_truncated_range() {
+        let range = range_10x1();
+        // Mimics `skip_empty_area=false` truncating to 3 rows before the caller's
+        // `start_row` is applied.
+        let truncated = Arc::new(range.range((0, 0), (2, 0)));
+        let err = apply_start_window(truncated, Some(5), None, None).unwrap_err();
+        assert!(err.to_string().contains("Invalid range"));
+    }
+
+    #[test]
+    fn start_window_rejects_end_col_past_the_sheet_width() {
+        let range = Arc::new(range_10x1());
+        let err = apply_start_window(range, None, None, Some(3)).unwrap_err();
+        assert!(err.to_string().contains("out of bounds"));
+    }
+
+    #[test]
+    fn start_window_slices_in_bounds_requests() {
+        let range = Arc::new(range_10x1());
+        let windowed = apply_start_window(range, Some(5), None, None).unwrap();
+        assert_eq!(windowed.rows().count(), 5);
+    }
+}
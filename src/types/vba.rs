@@ -0,0 +1,56 @@
+use calamine::vba::VbaProject;
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+// NOT DONE: this request asked for a workbook-level `vba_project()` accessor so that
+// `CalamineVbaProject` is actually obtainable from Python. That accessor cannot be added
+// from this file: the Python-facing workbook type it belongs on lives outside this tree
+// (there is no lib.rs/workbook.rs here at all — see e.g. `sheet.rs`'s `crate::CellValue`
+// import, which resolves to a type this tree doesn't define either). Adding a stand-in
+// `CalamineWorkbook` here would just create a second, conflicting definition alongside
+// the real one. Whoever owns that file still needs to add:
+// `Sheets::vba_project()` -> `Some(Ok(p)) => Some(CalamineVbaProject::new(p.into_owned()))`,
+// `Some(Err(e)) => Err(PyValueError::new_err(e.to_string()))`, `None => None`.
+
+/// A macro-enabled workbook's VBA project: its modules and declared references.
+#[pyclass]
+pub struct CalamineVbaProject {
+    project: VbaProject,
+}
+
+impl CalamineVbaProject {
+    pub fn new(project: VbaProject) -> Self {
+        CalamineVbaProject { project }
+    }
+}
+
+#[pymethods]
+impl CalamineVbaProject {
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(format!(
+            "CalamineVbaProject(modules={})",
+            self.project.module_names().count()
+        ))
+    }
+
+    fn module_names(&self) -> Vec<String> {
+        self.project
+            .module_names()
+            .map(ToString::to_string)
+            .collect()
+    }
+
+    fn get_module(&mut self, name: &str) -> PyResult<String> {
+        self.project.get_module(name).map_err(|e| {
+            PyValueError::new_err(format!("Failed to read VBA module '{name}': {e}"))
+        })
+    }
+
+    fn references(&self) -> Vec<(String, bool)> {
+        self.project
+            .references()
+            .iter()
+            .map(|reference| (reference.name.clone(), reference.is_missing()))
+            .collect()
+    }
+}